@@ -0,0 +1,23 @@
+//! De Casteljau helpers shared by the flattening, quadratic/cubic
+//! conversion, and bounds modules.
+
+use kurbo::Point;
+
+pub(crate) fn lerp(a: Point, b: Point, t: f64) -> Point {
+    Point::new(a.x + (b.x - a.x) * t, a.y + (b.y - a.y) * t)
+}
+
+pub(crate) fn quad_point(p0: Point, p1: Point, p2: Point, t: f64) -> Point {
+    let a = lerp(p0, p1, t);
+    let b = lerp(p1, p2, t);
+    lerp(a, b, t)
+}
+
+pub(crate) fn cubic_point(p0: Point, p1: Point, p2: Point, p3: Point, t: f64) -> Point {
+    let a = lerp(p0, p1, t);
+    let b = lerp(p1, p2, t);
+    let c = lerp(p2, p3, t);
+    let d = lerp(a, b, t);
+    let e = lerp(b, c, t);
+    lerp(d, e, t)
+}
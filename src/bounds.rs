@@ -0,0 +1,181 @@
+use kurbo::{Affine, Point, Rect};
+use norad::Name;
+
+use crate::bezier::{cubic_point, quad_point};
+use crate::segment::Segment;
+use crate::{Contour, Drawing};
+
+impl Drawing {
+    /// The control box of this drawing's contours, ignoring components.
+    /// Use [`Drawing::bounds_with_components`] to fold component geometry in.
+    pub fn bounds(&self) -> Rect {
+        self.contour_bounds().unwrap_or(Rect::ZERO)
+    }
+
+    /// Like [`Drawing::bounds`], but also folds in each component, resolving
+    /// its base glyph's bounds through `resolve` and transforming them by
+    /// the component's `Affine`. Components `resolve` can't answer for
+    /// (e.g. a missing base glyph) are skipped.
+    pub fn bounds_with_components(&self, resolve: impl Fn(&Name) -> Option<Rect>) -> Rect {
+        let mut rect = self.contour_bounds();
+        for component in &self.components {
+            if let Some(base_bounds) = resolve(&component.base) {
+                let transformed = transform_rect(component.transform, base_bounds);
+                rect = Some(union(rect, transformed));
+            }
+        }
+        rect.unwrap_or(Rect::ZERO)
+    }
+
+    fn contour_bounds(&self) -> Option<Rect> {
+        self.contours
+            .iter()
+            .map(Contour::bounds)
+            .fold(None, |acc, r| Some(union(acc, r)))
+    }
+}
+
+impl Contour {
+    /// The true control box of this contour: for curve segments this
+    /// includes the on-curve extrema rather than just the hull of `nodes`.
+    pub fn bounds(&self) -> Rect {
+        let (_, segments) = self.to_segments();
+        let Some(first) = segments.first() else {
+            return Rect::ZERO;
+        };
+
+        let start = first.to();
+        let mut rect = Rect::from_points(start, start);
+        let mut current = start;
+        for segment in &segments[1..] {
+            match *segment {
+                Segment::MoveTo(_) => unreachable!("only the first segment may be a MoveTo"),
+                Segment::LineTo(to) => rect = rect.union_pt(to),
+                Segment::QuadTo(ctrl, to) => {
+                    rect = rect.union_pt(to);
+                    for t in quad_extrema_ts(current, ctrl, to) {
+                        rect = rect.union_pt(quad_point(current, ctrl, to, t));
+                    }
+                }
+                Segment::CurveTo(ctrl1, ctrl2, to) => {
+                    rect = rect.union_pt(to);
+                    for t in cubic_extrema_ts(current, ctrl1, ctrl2, to) {
+                        rect = rect.union_pt(cubic_point(current, ctrl1, ctrl2, to, t));
+                    }
+                }
+            }
+            current = segment.to();
+        }
+        rect
+    }
+}
+
+fn union(acc: Option<Rect>, rect: Rect) -> Rect {
+    match acc {
+        Some(acc) => acc.union(rect),
+        None => rect,
+    }
+}
+
+fn transform_rect(affine: Affine, rect: Rect) -> Rect {
+    let corners = [
+        Point::new(rect.x0, rect.y0),
+        Point::new(rect.x1, rect.y0),
+        Point::new(rect.x1, rect.y1),
+        Point::new(rect.x0, rect.y1),
+    ];
+    let mut out = Rect::from_points(affine * corners[0], affine * corners[0]);
+    for corner in &corners[1..] {
+        out = out.union_pt(affine * *corner);
+    }
+    out
+}
+
+fn quad_extrema_ts(p0: Point, p1: Point, p2: Point) -> Vec<f64> {
+    [quad_axis_root(p0.x, p1.x, p2.x), quad_axis_root(p0.y, p1.y, p2.y)]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+fn quad_axis_root(p0: f64, p1: f64, p2: f64) -> Option<f64> {
+    let denom = p0 - 2.0 * p1 + p2;
+    if denom.abs() < 1e-12 {
+        return None;
+    }
+    let t = (p0 - p1) / denom;
+    (t > 0.0 && t < 1.0).then_some(t)
+}
+
+fn cubic_extrema_ts(p0: Point, c1: Point, c2: Point, p3: Point) -> Vec<f64> {
+    let mut ts = cubic_axis_roots(p0.x, c1.x, c2.x, p3.x);
+    ts.extend(cubic_axis_roots(p0.y, c1.y, c2.y, p3.y));
+    ts
+}
+
+/// Roots in (0, 1) of a*t^2 + b*t + c, the derivative of a cubic component.
+fn cubic_axis_roots(p0: f64, c1: f64, c2: f64, p3: f64) -> Vec<f64> {
+    let a = 3.0 * (-p0 + 3.0 * c1 - 3.0 * c2 + p3);
+    let b = 6.0 * (p0 - 2.0 * c1 + c2);
+    let c = 3.0 * (c1 - p0);
+
+    let mut roots = Vec::new();
+    if a.abs() < 1e-12 {
+        if b.abs() > 1e-12 {
+            let t = -c / b;
+            if t > 0.0 && t < 1.0 {
+                roots.push(t);
+            }
+        }
+        return roots;
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return roots;
+    }
+    let sqrt_discriminant = discriminant.sqrt();
+    for t in [
+        (-b + sqrt_discriminant) / (2.0 * a),
+        (-b - sqrt_discriminant) / (2.0 * a),
+    ] {
+        if t > 0.0 && t < 1.0 {
+            roots.push(t);
+        }
+    }
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{Component, Drawing, Node, PointType};
+
+    #[test]
+    fn bounds_include_quad_curve_extrema_beyond_the_endpoint_hull() {
+        // Endpoints both sit at y=0, but the curve bulges up to y=5 at its
+        // midpoint; a hull of just the on-curve points would miss that.
+        let contour = Contour::from_nodes(vec![
+            Node::new(0.0, 0.0, PointType::Move),
+            Node::new(5.0, 10.0, PointType::OffCurve),
+            Node::new(10.0, 0.0, PointType::QCurve),
+        ]);
+        assert_eq!(contour.bounds(), Rect::new(0.0, 0.0, 10.0, 5.0));
+    }
+
+    #[test]
+    fn bounds_with_components_transforms_and_unions_the_resolved_base() {
+        let mut drawing = Drawing::new();
+        drawing
+            .components
+            .push(Component::new("circle", Affine::translate((100.0, 0.0))));
+
+        let target = Name::new("circle").unwrap();
+        let rect = drawing.bounds_with_components(|name| {
+            (*name == target).then(|| Rect::new(0.0, 0.0, 10.0, 10.0))
+        });
+        assert_eq!(rect, Rect::new(100.0, 0.0, 110.0, 10.0));
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use norad::Name;
+
+use crate::Drawing;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecomposeError {
+    Cyclic(Name),
+}
+
+impl fmt::Display for DecomposeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecomposeError::Cyclic(name) => {
+                write!(f, "component '{name}' transitively references itself")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecomposeError {}
+
+impl Drawing {
+    /// Recursively replaces every `Component` with the (transformed)
+    /// geometry of its base glyph, resolved through `resolve`, flattening
+    /// `components` away entirely.
+    pub fn decompose(
+        &mut self,
+        resolve: impl Fn(&Name) -> Option<Drawing>,
+    ) -> Result<(), DecomposeError> {
+        *self = self.decomposed(resolve)?;
+        Ok(())
+    }
+
+    /// Non-consuming variant of [`Drawing::decompose`].
+    pub fn decomposed(
+        &self,
+        resolve: impl Fn(&Name) -> Option<Drawing>,
+    ) -> Result<Drawing, DecomposeError> {
+        let mut in_progress = HashSet::new();
+        decompose_into(self, &resolve, &mut in_progress)
+    }
+}
+
+fn decompose_into(
+    drawing: &Drawing,
+    resolve: &impl Fn(&Name) -> Option<Drawing>,
+    in_progress: &mut HashSet<Name>,
+) -> Result<Drawing, DecomposeError> {
+    let mut out = Drawing {
+        height_and_origin: drawing.height_and_origin,
+        width: drawing.width,
+        anchors: drawing.anchors.clone(),
+        components: Vec::new(),
+        contours: drawing.contours.clone(),
+    };
+
+    for component in &drawing.components {
+        if !in_progress.insert(component.base.clone()) {
+            return Err(DecomposeError::Cyclic(component.base.clone()));
+        }
+
+        let result = match resolve(&component.base) {
+            Some(base_drawing) => decompose_into(&base_drawing, resolve, in_progress),
+            None => Ok(Drawing::new()),
+        };
+
+        in_progress.remove(&component.base);
+        let mut expanded = result?;
+        expanded.apply_affine(component.transform);
+
+        out.anchors.extend(expanded.anchors);
+        out.contours.extend(expanded.contours);
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use kurbo::Affine;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{Component, Contour, Node, PointType};
+
+    fn name(s: &str) -> Name {
+        Name::new(s).unwrap()
+    }
+
+    #[test]
+    fn decompose_inlines_transformed_component_geometry() {
+        let mut dot = Drawing::new();
+        dot.contours.push(Contour::from_nodes(vec![
+            Node::new(0.0, 0.0, PointType::Line),
+            Node::new(1.0, 0.0, PointType::Line),
+            Node::new(0.0, 1.0, PointType::Line),
+        ]));
+
+        let mut composite = Drawing::new();
+        composite
+            .components
+            .push(Component::new("dot", Affine::translate((10.0, 20.0))));
+
+        let glyphs: HashMap<Name, Drawing> = HashMap::from([(name("dot"), dot.clone())]);
+        let result = composite
+            .decomposed(|base| glyphs.get(base).cloned())
+            .unwrap();
+
+        assert!(result.components.is_empty());
+        let mut expected_contour = dot.contours[0].clone();
+        expected_contour.apply_affine(Affine::translate((10.0, 20.0)));
+        assert_eq!(result.contours, vec![expected_contour]);
+    }
+
+    #[test]
+    fn decompose_rejects_a_cyclic_component_reference() {
+        let mut a = Drawing::new();
+        a.components
+            .push(Component::new("b", Affine::translate((1.0, 0.0))));
+        let mut b = Drawing::new();
+        b.components
+            .push(Component::new("a", Affine::translate((0.0, 1.0))));
+
+        let glyphs: HashMap<Name, Drawing> =
+            HashMap::from([(name("a"), a.clone()), (name("b"), b)]);
+
+        let result = a.decomposed(|base| glyphs.get(base).cloned());
+
+        assert_eq!(result, Err(DecomposeError::Cyclic(name("b"))));
+    }
+}
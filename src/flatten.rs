@@ -0,0 +1,145 @@
+use kurbo::Point;
+
+use crate::bezier::{cubic_point, quad_point};
+use crate::segment::Segment;
+use crate::{Contour, Drawing, Node, PointType};
+
+impl Drawing {
+    /// Replaces every curve segment in every contour with line segments that
+    /// stay within `tolerance` of the original curve. See
+    /// [`Contour::flatten`].
+    pub fn flatten(&self, tolerance: f64) -> Drawing {
+        let mut out = self.clone();
+        out.contours = self
+            .contours
+            .iter()
+            .map(|contour| contour.flatten(tolerance))
+            .collect();
+        out
+    }
+}
+
+impl Contour {
+    /// Converts all curve segments into line segments within a guaranteed
+    /// maximum deviation of `tolerance`, using uniform subdivision sized
+    /// from the control polygon's second-difference magnitude. Preserves the
+    /// contour's open/closed nature and the leading node's `Move`/
+    /// `SmoothMove` type for open contours.
+    pub fn flatten(&self, tolerance: f64) -> Contour {
+        let (closed, segments) = self.to_segments();
+        if segments.is_empty() {
+            return Contour::new();
+        }
+
+        let mut points = vec![segments[0].to()];
+        let mut current = points[0];
+        for segment in &segments[1..] {
+            match *segment {
+                Segment::MoveTo(_) => unreachable!("only the first segment may be a MoveTo"),
+                Segment::LineTo(to) => points.push(to),
+                Segment::QuadTo(ctrl, to) => {
+                    flatten_quad(current, ctrl, to, tolerance, &mut points)
+                }
+                Segment::CurveTo(ctrl1, ctrl2, to) => {
+                    flatten_cubic(current, ctrl1, ctrl2, to, tolerance, &mut points)
+                }
+            }
+            current = segment.to();
+        }
+
+        // A closed contour's segments close the loop back onto the start
+        // point, which `to_segments` already represents as `points[0]`.
+        if closed {
+            points.pop();
+        }
+
+        let mut nodes: Vec<Node> = points
+            .into_iter()
+            .map(|p| Node::new(p.x, p.y, PointType::Line))
+            .collect();
+        if !closed {
+            nodes[0].typ = self.nodes[0].typ.clone();
+        }
+        Contour::from_nodes(nodes)
+    }
+}
+
+fn flatten_quad(p0: Point, p1: Point, p2: Point, tolerance: f64, out: &mut Vec<Point>) {
+    let deviation = quad_deviation(p0, p1, p2);
+    let n = subdivisions(deviation, tolerance);
+    for i in 1..=n {
+        out.push(quad_point(p0, p1, p2, i as f64 / n as f64));
+    }
+}
+
+fn flatten_cubic(p0: Point, p1: Point, p2: Point, p3: Point, tolerance: f64, out: &mut Vec<Point>) {
+    let deviation = cubic_deviation(p0, p1, p2, p3);
+    let n = subdivisions(deviation, tolerance);
+    for i in 1..=n {
+        out.push(cubic_point(p0, p1, p2, p3, i as f64 / n as f64));
+    }
+}
+
+/// n = ceil(sqrt(deviation / (8 * tolerance))), with at least one segment.
+/// `tolerance` must be positive: dividing by a zero or negative tolerance
+/// would otherwise blow up to an unbounded subdivision count, so such
+/// inputs (and NaN) get the finest allowed subdivision,
+/// [`MAX_SUBDIVISIONS`], rather than trusting the raw division.
+fn subdivisions(deviation: f64, tolerance: f64) -> usize {
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        return MAX_SUBDIVISIONS;
+    }
+    let n = (deviation / (8.0 * tolerance)).sqrt().ceil();
+    if n.is_finite() {
+        (n as usize).clamp(1, MAX_SUBDIVISIONS)
+    } else {
+        MAX_SUBDIVISIONS
+    }
+}
+
+/// A hard ceiling on how finely one curve segment is ever subdivided, so a
+/// degenerate or non-positive `tolerance` can't exhaust memory or hang.
+const MAX_SUBDIVISIONS: usize = 1_000;
+
+fn quad_deviation(p0: Point, p1: Point, p2: Point) -> f64 {
+    let dx = p0.x - 2.0 * p1.x + p2.x;
+    let dy = p0.y - 2.0 * p1.y + p2.y;
+    (dx * dx + dy * dy).sqrt()
+}
+
+fn cubic_deviation(p0: Point, p1: Point, p2: Point, p3: Point) -> f64 {
+    let dx = p0.x - 3.0 * p1.x + 3.0 * p2.x - p3.x;
+    let dy = p0.y - 3.0 * p1.y + 3.0 * p2.y - p3.y;
+    (dx * dx + dy * dy).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn flatten_quad_produces_only_line_segments() {
+        let contour = Contour::from_nodes(vec![
+            Node::new(0.0, 0.0, PointType::Move),
+            Node::new(50.0, 100.0, PointType::OffCurve),
+            Node::new(100.0, 0.0, PointType::QCurve),
+        ]);
+        let flattened = contour.flatten(1.0);
+
+        assert!(flattened.nodes.len() > 2);
+        assert_eq!(flattened.nodes[0].typ, PointType::Move);
+        for node in &flattened.nodes[1..] {
+            assert_eq!(node.typ, PointType::Line);
+        }
+    }
+
+    #[test]
+    fn non_positive_tolerance_is_clamped_instead_of_hanging() {
+        assert_eq!(subdivisions(100.0, 0.0), MAX_SUBDIVISIONS);
+        assert_eq!(subdivisions(100.0, -1.0), MAX_SUBDIVISIONS);
+        assert_eq!(subdivisions(0.0, 0.0), MAX_SUBDIVISIONS);
+        assert_eq!(subdivisions(100.0, f64::NAN), MAX_SUBDIVISIONS);
+    }
+}
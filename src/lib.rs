@@ -1,9 +1,18 @@
 use kurbo::{Affine, Point};
 use norad::Name;
 
-use pen::PointPen;
+use pen::{PointPen, SegmentPen};
 
+mod bezier;
+
+pub mod bounds;
+pub mod decompose;
+pub mod flatten;
+pub mod orientation;
 pub mod pen;
+pub mod quadratic;
+pub mod segment;
+pub mod stroke;
 
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct Drawing {
@@ -74,6 +83,10 @@ impl Drawing {
     pub fn point_pen(&mut self) -> PointPen {
         pen::PointPen::new(self)
     }
+
+    pub fn segment_pen(&mut self) -> SegmentPen {
+        pen::SegmentPen::new(self)
+    }
 }
 
 impl Anchor {
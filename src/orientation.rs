@@ -0,0 +1,224 @@
+use kurbo::Point;
+
+use crate::segment::Segment;
+use crate::{Contour, Drawing};
+
+/// Tolerance used to flatten contours before testing point containment for
+/// nesting, in font units; well below anything meaningful for hole detection.
+const NESTING_FLATTEN_TOLERANCE: f64 = 1.0;
+
+impl Contour {
+    /// The area enclosed by this (closed) contour, computed exactly over its
+    /// Bezier segments via the Green's-theorem line integral `∮x dy`, rather
+    /// than just the polygon of on-curve points. Positive for a
+    /// counter-clockwise contour, negative for clockwise.
+    pub fn signed_area(&self) -> f64 {
+        let (_, segments) = self.to_segments();
+        let Some(first) = segments.first() else {
+            return 0.0;
+        };
+
+        let mut area = 0.0;
+        let mut current = first.to();
+        for segment in &segments[1..] {
+            area += match *segment {
+                Segment::MoveTo(_) => unreachable!("only the first segment may be a MoveTo"),
+                Segment::LineTo(to) => line_area(current, to),
+                Segment::QuadTo(ctrl, to) => quad_area(current, ctrl, to),
+                Segment::CurveTo(ctrl1, ctrl2, to) => cubic_area(current, ctrl1, ctrl2, to),
+            };
+            current = segment.to();
+        }
+        area
+    }
+
+    pub fn is_clockwise(&self) -> bool {
+        self.signed_area() < 0.0
+    }
+
+    /// Reverses the direction this contour is drawn in, swapping segment
+    /// endpoints and control points so the outline is unchanged but its
+    /// winding flips.
+    pub fn reverse(&self) -> Contour {
+        let (closed, segments) = self.to_segments();
+        if segments.is_empty() {
+            return Contour::new();
+        }
+
+        let mut points = Vec::with_capacity(segments.len());
+        for segment in &segments {
+            points.push(segment.to());
+        }
+
+        let mut reversed = vec![Segment::MoveTo(*points.last().unwrap())];
+        for i in (1..segments.len()).rev() {
+            let to = points[i - 1];
+            reversed.push(match segments[i] {
+                Segment::MoveTo(_) => unreachable!("only the first segment may be a MoveTo"),
+                Segment::LineTo(_) => Segment::LineTo(to),
+                Segment::QuadTo(ctrl, _) => Segment::QuadTo(ctrl, to),
+                Segment::CurveTo(ctrl1, ctrl2, _) => Segment::CurveTo(ctrl2, ctrl1, to),
+            });
+        }
+
+        Contour::from_segments(closed, &reversed)
+    }
+}
+
+impl Drawing {
+    /// Enforces the UFO/PostScript winding convention: counter-clockwise
+    /// outer contours, clockwise holes, determined by point-in-contour
+    /// nesting depth against each contour's flattened polygon.
+    pub fn correct_direction(&self) -> Drawing {
+        let mut out = self.clone();
+
+        let polygons: Vec<Vec<Point>> = out
+            .contours
+            .iter()
+            .map(|contour| {
+                contour
+                    .flatten(NESTING_FLATTEN_TOLERANCE)
+                    .nodes
+                    .iter()
+                    .map(|node| node.pt)
+                    .collect()
+            })
+            .collect();
+
+        for i in 0..out.contours.len() {
+            let Some(&probe) = polygons[i].first() else {
+                continue;
+            };
+            let depth = (0..polygons.len())
+                .filter(|&j| j != i && contains_point(&polygons[j], probe))
+                .count();
+            let want_clockwise = depth % 2 == 1;
+            if out.contours[i].is_clockwise() != want_clockwise {
+                out.contours[i] = out.contours[i].reverse();
+            }
+        }
+
+        out
+    }
+}
+
+fn line_area(p0: Point, p1: Point) -> f64 {
+    (p0.x + p1.x) / 2.0 * (p1.y - p0.y)
+}
+
+fn quad_area(p0: Point, p1: Point, p2: Point) -> f64 {
+    let c0 = p0.x;
+    let c1 = 2.0 * (p1.x - p0.x);
+    let c2 = p0.x - 2.0 * p1.x + p2.x;
+    let d0 = 2.0 * (p1.y - p0.y);
+    let d1 = 2.0 * (p0.y - 2.0 * p1.y + p2.y);
+    c0 * d0 + (c0 * d1 + c1 * d0) / 2.0 + (c1 * d1 + c2 * d0) / 3.0 + (c2 * d1) / 4.0
+}
+
+fn cubic_area(p0: Point, p1: Point, p2: Point, p3: Point) -> f64 {
+    let c0 = p0.x;
+    let c1 = 3.0 * (p1.x - p0.x);
+    let c2 = 3.0 * (p0.x - 2.0 * p1.x + p2.x);
+    let c3 = -p0.x + 3.0 * p1.x - 3.0 * p2.x + p3.x;
+    let e1 = 3.0 * (p1.y - p0.y);
+    let e2 = 3.0 * (p0.y - 2.0 * p1.y + p2.y);
+    let e3 = -p0.y + 3.0 * p1.y - 3.0 * p2.y + p3.y;
+    c0 * e1
+        + (2.0 * c0 * e2 + c1 * e1) / 2.0
+        + (3.0 * c0 * e3 + 2.0 * c1 * e2 + c2 * e1) / 3.0
+        + (3.0 * c1 * e3 + 2.0 * c2 * e2 + c3 * e1) / 4.0
+        + (3.0 * c2 * e3 + 2.0 * c3 * e2) / 5.0
+        + (3.0 * c3 * e3) / 6.0
+}
+
+/// Ray-casting point-in-polygon test.
+fn contains_point(polygon: &[Point], pt: Point) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    let mut inside = false;
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        let pi = polygon[i];
+        let pj = polygon[j];
+        if (pi.y > pt.y) != (pj.y > pt.y) {
+            let x_intersect = (pj.x - pi.x) * (pt.y - pi.y) / (pj.y - pi.y) + pi.x;
+            if pt.x < x_intersect {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{Node, PointType};
+
+    fn square(counter_clockwise: bool) -> Contour {
+        let mut corners = vec![
+            Node::new(0.0, 0.0, PointType::Line),
+            Node::new(10.0, 0.0, PointType::Line),
+            Node::new(10.0, 10.0, PointType::Line),
+            Node::new(0.0, 10.0, PointType::Line),
+        ];
+        if !counter_clockwise {
+            corners.reverse();
+        }
+        Contour::from_nodes(corners)
+    }
+
+    #[test]
+    fn reverse_flips_the_sign_of_signed_area_but_not_its_magnitude() {
+        let ccw = square(true);
+        let cw = ccw.reverse();
+
+        assert_eq!(ccw.signed_area(), 100.0);
+        assert!(!ccw.is_clockwise());
+        assert_eq!(cw.signed_area(), -100.0);
+        assert!(cw.is_clockwise());
+    }
+
+    #[test]
+    fn correct_direction_flips_a_clockwise_outer_contour_to_counter_clockwise() {
+        let mut drawing = Drawing::new();
+        drawing.contours.push(square(false));
+
+        let corrected = drawing.correct_direction();
+
+        assert_eq!(corrected.contours.len(), 1);
+        assert!(!corrected.contours[0].is_clockwise());
+    }
+
+    #[test]
+    fn correct_direction_makes_a_hole_clockwise_inside_a_counter_clockwise_outer() {
+        let outer = Contour::from_nodes(vec![
+            Node::new(0.0, 0.0, PointType::Line),
+            Node::new(20.0, 0.0, PointType::Line),
+            Node::new(20.0, 20.0, PointType::Line),
+            Node::new(0.0, 20.0, PointType::Line),
+        ]);
+        // A hole, wound the same (counter-clockwise) way as the outer shape,
+        // which is wrong for a nested contour and should get reversed.
+        let hole = Contour::from_nodes(vec![
+            Node::new(5.0, 5.0, PointType::Line),
+            Node::new(15.0, 5.0, PointType::Line),
+            Node::new(15.0, 15.0, PointType::Line),
+            Node::new(5.0, 15.0, PointType::Line),
+        ]);
+
+        let mut drawing = Drawing::new();
+        drawing.contours.push(outer);
+        drawing.contours.push(hole);
+
+        let corrected = drawing.correct_direction();
+
+        assert!(!corrected.contours[0].is_clockwise());
+        assert!(corrected.contours[1].is_clockwise());
+    }
+}
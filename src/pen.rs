@@ -1,6 +1,7 @@
-use kurbo::Affine;
+use kurbo::{Affine, Point};
 
 use super::{Component, Contour, Drawing, Node, PointType};
+use crate::segment::Segment;
 
 #[derive(Debug)]
 pub struct PointPen<'a> {
@@ -43,3 +44,77 @@ impl<'a> PointPen<'a> {
             .push(Component::new(glyph_name, transform));
     }
 }
+
+/// A segment-oriented counterpart to [`PointPen`], for callers that think in
+/// terms of `MoveTo`/`LineTo`/`QuadTo`/`CurveTo` events rather than raw nodes.
+#[derive(Debug)]
+pub struct SegmentPen<'a> {
+    current_contour: Option<Vec<Segment>>,
+    drawing: &'a mut Drawing,
+}
+
+impl<'a> SegmentPen<'a> {
+    pub fn new(drawing: &'a mut Drawing) -> Self {
+        Self {
+            current_contour: None,
+            drawing,
+        }
+    }
+
+    pub fn move_to(&mut self, x: f64, y: f64) {
+        assert!(self.current_contour.is_none());
+        self.current_contour = Some(vec![Segment::MoveTo(Point::new(x, y))]);
+    }
+
+    pub fn line_to(&mut self, x: f64, y: f64) {
+        self.push(Segment::LineTo(Point::new(x, y)));
+    }
+
+    pub fn quad_to(&mut self, ctrl: (f64, f64), to: (f64, f64)) {
+        self.push(Segment::QuadTo(
+            Point::new(ctrl.0, ctrl.1),
+            Point::new(to.0, to.1),
+        ));
+    }
+
+    pub fn curve_to(&mut self, ctrl1: (f64, f64), ctrl2: (f64, f64), to: (f64, f64)) {
+        self.push(Segment::CurveTo(
+            Point::new(ctrl1.0, ctrl1.1),
+            Point::new(ctrl2.0, ctrl2.1),
+            Point::new(to.0, to.1),
+        ));
+    }
+
+    /// Ends the current contour as closed, wrapping back to its start.
+    pub fn close_path(&mut self) {
+        if let Some(segments) = self.current_contour.as_mut() {
+            let start = segments[0].to();
+            if segments.last().map(Segment::to) != Some(start) {
+                segments.push(Segment::LineTo(start));
+            }
+        }
+        self.finish(true);
+    }
+
+    /// Ends the current contour as open.
+    pub fn end_path(&mut self) {
+        self.finish(false);
+    }
+
+    fn push(&mut self, segment: Segment) {
+        self.current_contour
+            .as_mut()
+            .expect("move_to must be called before adding further segments")
+            .push(segment);
+    }
+
+    fn finish(&mut self, closed: bool) {
+        let segments = self
+            .current_contour
+            .take()
+            .expect("close_path/end_path called without a preceding move_to");
+        self.drawing
+            .contours
+            .push(Contour::from_segments(closed, &segments));
+    }
+}
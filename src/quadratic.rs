@@ -0,0 +1,194 @@
+use kurbo::Point;
+
+use crate::bezier::{cubic_point, lerp, quad_point};
+use crate::segment::Segment;
+use crate::Contour;
+
+impl Contour {
+    /// Approximates every cubic segment with one or more quadratics, each
+    /// within `max_error` of the original curve (the Colomitchi midpoint
+    /// approximation, recursively subdividing at t=0.5 until flat enough).
+    /// Existing line and quadratic segments pass through unchanged.
+    pub fn to_quadratic(&self, max_error: f64) -> Contour {
+        let (closed, segments) = self.to_segments();
+        if segments.is_empty() {
+            return Contour::new();
+        }
+
+        let mut out = vec![segments[0]];
+        let mut current = segments[0].to();
+        for segment in &segments[1..] {
+            match *segment {
+                Segment::MoveTo(_) => unreachable!("only the first segment may be a MoveTo"),
+                Segment::LineTo(_) | Segment::QuadTo(..) => out.push(*segment),
+                Segment::CurveTo(ctrl1, ctrl2, to) => {
+                    cubic_to_quadratics(current, ctrl1, ctrl2, to, max_error, 0, &mut out)
+                }
+            }
+            current = segment.to();
+        }
+
+        Contour::from_segments(closed, &out)
+    }
+
+    /// Converts every quadratic segment to an exactly equivalent cubic.
+    /// Existing line and cubic segments pass through unchanged.
+    pub fn to_cubic(&self) -> Contour {
+        let (closed, segments) = self.to_segments();
+        if segments.is_empty() {
+            return Contour::new();
+        }
+
+        let mut out = vec![segments[0]];
+        let mut current = segments[0].to();
+        for segment in &segments[1..] {
+            match *segment {
+                Segment::MoveTo(_) => unreachable!("only the first segment may be a MoveTo"),
+                Segment::LineTo(_) | Segment::CurveTo(..) => out.push(*segment),
+                Segment::QuadTo(ctrl, to) => {
+                    let ctrl1 = Point::new(
+                        current.x + 2.0 / 3.0 * (ctrl.x - current.x),
+                        current.y + 2.0 / 3.0 * (ctrl.y - current.y),
+                    );
+                    let ctrl2 = Point::new(
+                        to.x + 2.0 / 3.0 * (ctrl.x - to.x),
+                        to.y + 2.0 / 3.0 * (ctrl.y - to.y),
+                    );
+                    out.push(Segment::CurveTo(ctrl1, ctrl2, to));
+                }
+            }
+            current = segment.to();
+        }
+
+        Contour::from_segments(closed, &out)
+    }
+}
+
+/// Caps how many times a single cubic is bisected, so a near-cusp curve or
+/// a `max_error` of zero (where floating-point noise can keep `error` just
+/// above the threshold forever) can't recurse until the stack overflows.
+/// 24 levels already subdivides into 2^24 (~16M) pieces, far past any
+/// useful curve resolution.
+const MAX_SPLIT_DEPTH: u32 = 24;
+
+fn cubic_to_quadratics(
+    p0: Point,
+    c1: Point,
+    c2: Point,
+    p3: Point,
+    max_error: f64,
+    depth: u32,
+    out: &mut Vec<Segment>,
+) {
+    let ctrl = Point::new(
+        (3.0 * (c1.x + c2.x) - (p0.x + p3.x)) / 4.0,
+        (3.0 * (c1.y + c2.y) - (p0.y + p3.y)) / 4.0,
+    );
+
+    let error = max_deviation(p0, c1, c2, p3, ctrl);
+
+    if error <= max_error || depth >= MAX_SPLIT_DEPTH {
+        out.push(Segment::QuadTo(ctrl, p3));
+        return;
+    }
+
+    let (left, right) = split_cubic(p0, c1, c2, p3);
+    cubic_to_quadratics(left.0, left.1, left.2, left.3, max_error, depth + 1, out);
+    cubic_to_quadratics(
+        right.0,
+        right.1,
+        right.2,
+        right.3,
+        max_error,
+        depth + 1,
+        out,
+    );
+}
+
+/// Sample count used by [`max_deviation`]. Chosen so no sample lands on
+/// `t=0.5` exactly, since a cubic symmetric about its midpoint (as the
+/// series' own test curve is) would otherwise make a single midpoint
+/// sample match the quadratic exactly regardless of the real error.
+const ERROR_SAMPLES: usize = 9;
+
+/// Approximates the worst-case deviation between the cubic and its
+/// candidate quadratic by sampling several interior points rather than
+/// just the midpoint, which a symmetric cubic can make deceptively exact.
+fn max_deviation(p0: Point, c1: Point, c2: Point, p3: Point, quad_ctrl: Point) -> f64 {
+    (1..ERROR_SAMPLES)
+        .map(|i| i as f64 / ERROR_SAMPLES as f64)
+        .map(|t| {
+            let cubic_pt = cubic_point(p0, c1, c2, p3, t);
+            let quad_pt = quad_point(p0, quad_ctrl, p3, t);
+            (cubic_pt.x - quad_pt.x).hypot(cubic_pt.y - quad_pt.y)
+        })
+        .fold(0.0, f64::max)
+}
+
+type Cubic = (Point, Point, Point, Point);
+
+fn split_cubic(p0: Point, p1: Point, p2: Point, p3: Point) -> (Cubic, Cubic) {
+    let p01 = lerp(p0, p1, 0.5);
+    let p12 = lerp(p1, p2, 0.5);
+    let p23 = lerp(p2, p3, 0.5);
+    let p012 = lerp(p01, p12, 0.5);
+    let p123 = lerp(p12, p23, 0.5);
+    let mid = lerp(p012, p123, 0.5);
+    ((p0, p01, p012, mid), (mid, p123, p23, p3))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{Node, PointType};
+
+    #[test]
+    fn to_quadratic_stays_close_to_the_original_cubic() {
+        let contour = Contour::from_nodes(vec![
+            Node::new(0.0, 0.0, PointType::Move),
+            Node::new(30.0, 100.0, PointType::OffCurve),
+            Node::new(70.0, 100.0, PointType::OffCurve),
+            Node::new(100.0, 0.0, PointType::Curve),
+        ]);
+
+        let quadratic = contour.to_quadratic(0.01);
+        for node in &quadratic.nodes {
+            assert_ne!(node.typ, PointType::Curve);
+        }
+
+        let cubic_mid = cubic_point(
+            Point::new(0.0, 0.0),
+            Point::new(30.0, 100.0),
+            Point::new(70.0, 100.0),
+            Point::new(100.0, 0.0),
+            0.5,
+        );
+        let (_, segments) = quadratic.to_segments();
+        let closest = segments
+            .iter()
+            .map(|s| s.to())
+            .map(|p| (p.x - cubic_mid.x).hypot(p.y - cubic_mid.y))
+            .fold(f64::INFINITY, f64::min);
+        assert!(closest < 1.0);
+    }
+
+    #[test]
+    fn zero_max_error_bails_out_at_the_depth_cap_instead_of_recursing_forever() {
+        // Start two levels short of the cap so the recursion only has to
+        // bottom out twice over, rather than actually walking all the way
+        // down from zero (which the depth guard alone is responsible for).
+        let mut out = Vec::new();
+        cubic_to_quadratics(
+            Point::new(0.0, 0.0),
+            Point::new(30.0, 100.0),
+            Point::new(70.0, 100.0),
+            Point::new(100.0, 0.0),
+            0.0,
+            MAX_SPLIT_DEPTH - 2,
+            &mut out,
+        );
+        assert_eq!(out.len(), 1 << 2);
+    }
+}
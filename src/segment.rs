@@ -0,0 +1,328 @@
+use kurbo::{Point, Vec2};
+
+use crate::{Contour, Node, PointType};
+
+/// A single drawing instruction in the style of a pen's `*To` callbacks,
+/// as opposed to the raw, UFO-flavoured [`Node`] representation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Segment {
+    MoveTo(Point),
+    LineTo(Point),
+    QuadTo(Point, Point),
+    CurveTo(Point, Point, Point),
+}
+
+impl Segment {
+    pub fn to(&self) -> Point {
+        match *self {
+            Segment::MoveTo(p) => p,
+            Segment::LineTo(p) => p,
+            Segment::QuadTo(_, p) => p,
+            Segment::CurveTo(_, _, p) => p,
+        }
+    }
+}
+
+impl Contour {
+    /// Groups this contour's nodes into segments, returning whether the
+    /// contour is closed alongside the segment stream. The stream always
+    /// opens with a `MoveTo` carrying the start point, even for closed
+    /// contours, where it otherwise has no `Node` counterpart; a closed
+    /// stream's final segment returns to that same point.
+    pub fn to_segments(&self) -> (bool, Vec<Segment>) {
+        let nodes = &self.nodes;
+        if nodes.is_empty() {
+            return (false, Vec::new());
+        }
+
+        let closed = !matches!(nodes[0].typ, PointType::Move | PointType::SmoothMove);
+
+        let mut ordered: Vec<&Node> = nodes.iter().collect();
+        if closed {
+            match ordered.iter().position(|n| n.typ != PointType::OffCurve) {
+                Some(first_on_curve) => ordered.rotate_left(first_on_curve),
+                // A fully implied TrueType contour: every point is off-curve,
+                // so there's no real on-curve node to rotate to. Start at the
+                // implied midpoint between the last and first off-curves,
+                // the same way interior runs of 3+ off-curves are bridged.
+                None => return (true, all_off_curve_segments(&ordered)),
+            }
+        }
+
+        let start = ordered[0].pt;
+        let mut segments = vec![Segment::MoveTo(start)];
+        let mut off_curve: Vec<Point> = Vec::new();
+
+        let steps = if closed {
+            ordered.len()
+        } else {
+            ordered.len() - 1
+        };
+        for i in 1..=steps {
+            let node = ordered[i % ordered.len()];
+            if node.typ == PointType::OffCurve {
+                off_curve.push(node.pt);
+                continue;
+            }
+
+            let to = node.pt;
+            match off_curve.len() {
+                0 => segments.push(Segment::LineTo(to)),
+                1 => segments.push(Segment::QuadTo(off_curve[0], to)),
+                2 => segments.push(Segment::CurveTo(off_curve[0], off_curve[1], to)),
+                _ => {
+                    // TrueType's implied-on-curve convention: each pair of
+                    // consecutive off-curves is bridged by the midpoint
+                    // between them, standing in for an omitted on-curve point.
+                    for pair in off_curve.windows(2) {
+                        segments.push(Segment::QuadTo(pair[0], midpoint(pair[0], pair[1])));
+                    }
+                    segments.push(Segment::QuadTo(*off_curve.last().unwrap(), to));
+                }
+            }
+            off_curve.clear();
+        }
+
+        (closed, segments)
+    }
+
+    /// The inverse of [`Contour::to_segments`]. `segments` must start with a
+    /// `MoveTo` giving the starting point. On-curve points are marked
+    /// `Smooth*` whenever the incoming and outgoing tangents at that point
+    /// are collinear and point the same way.
+    pub fn from_segments(closed: bool, segments: &[Segment]) -> Contour {
+        assert!(
+            matches!(segments.first(), Some(Segment::MoveTo(_))),
+            "a contour's segments must start with a MoveTo"
+        );
+        let start = segments[0].to();
+        let body = &segments[1..];
+
+        let mut froms = Vec::with_capacity(body.len());
+        let mut current = start;
+        for segment in body {
+            froms.push(current);
+            current = segment.to();
+        }
+
+        let mut nodes = Vec::new();
+        if !closed {
+            nodes.push(Node::new(start.x, start.y, PointType::Move));
+        }
+
+        for (i, segment) in body.iter().enumerate() {
+            let is_closing = closed && i == body.len() - 1;
+            match *segment {
+                Segment::MoveTo(_) => panic!("a contour may only have one leading MoveTo"),
+                Segment::LineTo(to) => {
+                    if !is_closing {
+                        nodes.push(Node::new(to.x, to.y, PointType::Line));
+                    }
+                }
+                Segment::QuadTo(ctrl, to) => {
+                    nodes.push(Node::new(ctrl.x, ctrl.y, PointType::OffCurve));
+                    if !is_closing {
+                        nodes.push(Node::new(to.x, to.y, PointType::QCurve));
+                    }
+                }
+                Segment::CurveTo(ctrl1, ctrl2, to) => {
+                    nodes.push(Node::new(ctrl1.x, ctrl1.y, PointType::OffCurve));
+                    nodes.push(Node::new(ctrl2.x, ctrl2.y, PointType::OffCurve));
+                    if !is_closing {
+                        nodes.push(Node::new(to.x, to.y, PointType::Curve));
+                    }
+                }
+            }
+
+            if !is_closing && i + 1 < body.len() {
+                let smooth = is_smooth(
+                    tangent_in(segment, froms[i]),
+                    tangent_out(&body[i + 1], froms[i + 1]),
+                );
+                if smooth {
+                    let on_curve = nodes.last_mut().unwrap();
+                    on_curve.typ = smooth_variant(&on_curve.typ);
+                }
+            }
+        }
+
+        if closed {
+            if body.is_empty() {
+                // A closed contour with nothing but its leading MoveTo (e.g.
+                // `move_to` immediately followed by `close_path`) has no
+                // segment to derive a node type from; fall back to a
+                // degenerate single on-curve point rather than indexing into
+                // the empty `body`.
+                nodes.push(Node::new(start.x, start.y, PointType::Line));
+            } else {
+                let last = body.len() - 1;
+                let smooth = is_smooth(
+                    tangent_in(&body[last], froms[last]),
+                    tangent_out(&body[0], froms[0]),
+                );
+                let typ = on_curve_type(&body[last], smooth);
+                nodes.insert(0, Node::new(start.x, start.y, typ));
+            }
+        }
+
+        Contour::from_nodes(nodes)
+    }
+}
+
+/// Segments for a closed, fully implied contour (every node off-curve):
+/// each off-curve point is a quadratic's control, and the on-curve points
+/// between them are the midpoints implied by the TrueType convention.
+fn all_off_curve_segments(ordered: &[&Node]) -> Vec<Segment> {
+    let n = ordered.len();
+    let start = midpoint(ordered[n - 1].pt, ordered[0].pt);
+    let mut segments = vec![Segment::MoveTo(start)];
+    for i in 0..n {
+        let ctrl = ordered[i].pt;
+        let to = midpoint(ordered[i].pt, ordered[(i + 1) % n].pt);
+        segments.push(Segment::QuadTo(ctrl, to));
+    }
+    segments
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0)
+}
+
+fn tangent_out(segment: &Segment, from: Point) -> Vec2 {
+    match *segment {
+        Segment::MoveTo(_) => Vec2::ZERO,
+        Segment::LineTo(to) => to - from,
+        Segment::QuadTo(ctrl, _) => ctrl - from,
+        Segment::CurveTo(ctrl1, ..) => ctrl1 - from,
+    }
+}
+
+fn tangent_in(segment: &Segment, from: Point) -> Vec2 {
+    match *segment {
+        Segment::MoveTo(_) => Vec2::ZERO,
+        Segment::LineTo(to) => to - from,
+        Segment::QuadTo(ctrl, to) => to - ctrl,
+        Segment::CurveTo(_, ctrl2, to) => to - ctrl2,
+    }
+}
+
+const SMOOTH_COSINE_TOLERANCE: f64 = 1e-3;
+
+fn is_smooth(incoming: Vec2, outgoing: Vec2) -> bool {
+    match (normalize(incoming), normalize(outgoing)) {
+        (Some(a), Some(b)) => a.dot(b) > 1.0 - SMOOTH_COSINE_TOLERANCE,
+        _ => false,
+    }
+}
+
+fn normalize(v: Vec2) -> Option<Vec2> {
+    let len = (v.x * v.x + v.y * v.y).sqrt();
+    if len < 1e-9 {
+        None
+    } else {
+        Some(Vec2::new(v.x / len, v.y / len))
+    }
+}
+
+fn on_curve_type(segment: &Segment, smooth: bool) -> PointType {
+    match (*segment, smooth) {
+        (Segment::LineTo(_), false) => PointType::Line,
+        (Segment::LineTo(_), true) => PointType::SmoothLine,
+        (Segment::QuadTo(..), false) => PointType::QCurve,
+        (Segment::QuadTo(..), true) => PointType::SmoothQCurve,
+        (Segment::CurveTo(..), false) => PointType::Curve,
+        (Segment::CurveTo(..), true) => PointType::SmoothCurve,
+        (Segment::MoveTo(_), false) => PointType::Move,
+        (Segment::MoveTo(_), true) => PointType::SmoothMove,
+    }
+}
+
+fn smooth_variant(typ: &PointType) -> PointType {
+    match typ {
+        PointType::Line => PointType::SmoothLine,
+        PointType::Curve => PointType::SmoothCurve,
+        PointType::QCurve => PointType::SmoothQCurve,
+        PointType::Move => PointType::SmoothMove,
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn round_trip_closed_line_triangle() {
+        let contour = Contour::from_nodes(vec![
+            Node::new(0.0, 0.0, PointType::Line),
+            Node::new(10.0, 0.0, PointType::Line),
+            Node::new(0.0, 10.0, PointType::Line),
+        ]);
+        let (closed, segments) = contour.to_segments();
+        assert!(closed);
+        assert_eq!(
+            segments,
+            vec![
+                Segment::MoveTo(Point::new(0.0, 0.0)),
+                Segment::LineTo(Point::new(10.0, 0.0)),
+                Segment::LineTo(Point::new(0.0, 10.0)),
+                Segment::LineTo(Point::new(0.0, 0.0)),
+            ]
+        );
+        assert_eq!(Contour::from_segments(closed, &segments), contour);
+    }
+
+    #[test]
+    fn round_trip_open_quad_curve() {
+        let contour = Contour::from_nodes(vec![
+            Node::new(0.0, 0.0, PointType::Move),
+            Node::new(5.0, 10.0, PointType::OffCurve),
+            Node::new(10.0, 0.0, PointType::QCurve),
+        ]);
+        let (closed, segments) = contour.to_segments();
+        assert!(!closed);
+        assert_eq!(Contour::from_segments(closed, &segments), contour);
+    }
+
+    #[test]
+    fn fully_implied_contour_is_not_dropped() {
+        // A 4-point TrueType-style circle with no real on-curve points: every
+        // node is off-curve, so the on-curve points are entirely implied by
+        // midpoints between consecutive controls.
+        let contour = Contour::from_nodes(vec![
+            Node::new(10.0, 0.0, PointType::OffCurve),
+            Node::new(0.0, 10.0, PointType::OffCurve),
+            Node::new(-10.0, 0.0, PointType::OffCurve),
+            Node::new(0.0, -10.0, PointType::OffCurve),
+        ]);
+
+        let (closed, segments) = contour.to_segments();
+        assert!(closed);
+        assert_eq!(
+            segments,
+            vec![
+                Segment::MoveTo(Point::new(5.0, -5.0)),
+                Segment::QuadTo(Point::new(10.0, 0.0), Point::new(5.0, 5.0)),
+                Segment::QuadTo(Point::new(0.0, 10.0), Point::new(-5.0, 5.0)),
+                Segment::QuadTo(Point::new(-10.0, 0.0), Point::new(-5.0, -5.0)),
+                Segment::QuadTo(Point::new(0.0, -10.0), Point::new(5.0, -5.0)),
+            ]
+        );
+
+        // The shape survives: it encloses a real, positive area rather than
+        // collapsing to the single point a lost contour would report.
+        let rebuilt = Contour::from_segments(closed, &segments);
+        assert!(rebuilt.signed_area() > 50.0);
+    }
+
+    #[test]
+    fn closed_contour_with_only_a_move_does_not_panic() {
+        // Mirrors `pen.move_to(...); pen.close_path();`: a closed contour
+        // whose only segment is the leading MoveTo, with nothing in `body`.
+        let segments = vec![Segment::MoveTo(Point::new(1.0, 2.0))];
+        let contour = Contour::from_segments(true, &segments);
+        assert_eq!(contour.nodes, vec![Node::new(1.0, 2.0, PointType::Line)]);
+    }
+}
@@ -0,0 +1,381 @@
+use kurbo::{Point, Vec2};
+
+use crate::segment::Segment;
+use crate::{Contour, Drawing, PointType};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineJoin {
+    Miter,
+    Round,
+    Bevel,
+}
+
+impl Drawing {
+    /// Expands every contour's outline into a filled shape `width` units
+    /// wide, by flattening to a polyline and offsetting each edge by
+    /// `width / 2` on either side. Closed contours become an outer contour
+    /// plus an inner, oppositely-wound hole; open contours become a single
+    /// closed contour capped at both ends.
+    pub fn stroke(&self, width: f64, cap: LineCap, join: LineJoin, miter_limit: f64) -> Drawing {
+        let flatten_tolerance = (width / 20.0).max(0.1);
+        let mut out = self.clone();
+        out.contours = self
+            .contours
+            .iter()
+            .flat_map(|contour| {
+                stroke_contour(
+                    &contour.flatten(flatten_tolerance),
+                    width,
+                    cap,
+                    join,
+                    miter_limit,
+                )
+            })
+            .collect();
+        out
+    }
+}
+
+fn stroke_contour(
+    flattened: &Contour,
+    width: f64,
+    cap: LineCap,
+    join: LineJoin,
+    miter_limit: f64,
+) -> Vec<Contour> {
+    let closed = match flattened.nodes.first() {
+        Some(node) => !matches!(node.typ, PointType::Move | PointType::SmoothMove),
+        None => false,
+    };
+    let pts: Vec<Point> = flattened.nodes.iter().map(|node| node.pt).collect();
+    if pts.len() < 2 {
+        return Vec::new();
+    }
+
+    let half_width = width / 2.0;
+    if closed {
+        let outer = Contour::from_segments(
+            true,
+            &offset_segments(&pts, true, half_width, join, miter_limit),
+        );
+        let inner = Contour::from_segments(
+            true,
+            &offset_segments(&pts, true, -half_width, join, miter_limit),
+        )
+        .reverse();
+        vec![outer, inner]
+    } else {
+        vec![stroke_open_contour(
+            &pts,
+            half_width,
+            cap,
+            join,
+            miter_limit,
+        )]
+    }
+}
+
+fn stroke_open_contour(
+    pts: &[Point],
+    half_width: f64,
+    cap: LineCap,
+    join: LineJoin,
+    miter_limit: f64,
+) -> Contour {
+    let left = offset_segments(pts, false, half_width, join, miter_limit);
+
+    let mut reversed_pts = pts.to_vec();
+    reversed_pts.reverse();
+    let right_rev = offset_segments(&reversed_pts, false, half_width, join, miter_limit);
+
+    let mut all = left.clone();
+
+    let end_center = pts[pts.len() - 1];
+    let end_from = all.last().unwrap().to();
+    let end_to = right_rev[0].to();
+    let end_outward = unit(pts[pts.len() - 1] - pts[pts.len() - 2]);
+    add_cap(
+        end_center,
+        end_from,
+        end_to,
+        half_width,
+        end_outward,
+        cap,
+        &mut all,
+    );
+
+    all.extend(right_rev[1..].iter().copied());
+
+    let start_center = pts[0];
+    let start_from = all.last().unwrap().to();
+    let start_to = left[0].to();
+    let start_outward = -unit(pts[1] - pts[0]);
+    add_cap(
+        start_center,
+        start_from,
+        start_to,
+        half_width,
+        start_outward,
+        cap,
+        &mut all,
+    );
+
+    Contour::from_segments(true, &all)
+}
+
+fn add_cap(
+    center: Point,
+    from: Point,
+    to: Point,
+    radius: f64,
+    outward: Vec2,
+    cap: LineCap,
+    out: &mut Vec<Segment>,
+) {
+    match cap {
+        LineCap::Butt => out.push(Segment::LineTo(to)),
+        LineCap::Round => {
+            let a0 = (from.y - center.y).atan2(from.x - center.x);
+            let mut delta = signed_angle_between(from - center, to - center);
+            // `from` and `to` are opposite each other, so the sign of `delta`
+            // is arbitrary; sweep through the side facing `outward`.
+            let mid = a0 + delta / 2.0;
+            if dot(Vec2::new(mid.cos(), mid.sin()), outward) < 0.0 {
+                delta -= std::f64::consts::TAU.copysign(delta);
+            }
+            arc_to_quadratics(center, radius, a0, a0 + delta, out);
+        }
+        LineCap::Square => {
+            out.push(Segment::LineTo(from + outward * radius));
+            out.push(Segment::LineTo(to + outward * radius));
+            out.push(Segment::LineTo(to));
+        }
+    }
+}
+
+/// Offsets a polyline by `distance` to either side (sign picks the side),
+/// stitching the offset edges back together at each vertex with `join`.
+fn offset_segments(
+    pts: &[Point],
+    closed: bool,
+    distance: f64,
+    join: LineJoin,
+    miter_limit: f64,
+) -> Vec<Segment> {
+    let n = pts.len();
+    let edge_count = if closed { n } else { n - 1 };
+
+    let dirs: Vec<Vec2> = (0..edge_count)
+        .map(|i| unit(pts[(i + 1) % n] - pts[i]))
+        .collect();
+    let normals: Vec<Vec2> = dirs.iter().map(|d| Vec2::new(-d.y, d.x)).collect();
+
+    let mut segments = vec![Segment::MoveTo(pts[0] + normals[0] * distance)];
+    for edge in 0..edge_count {
+        let b = pts[(edge + 1) % n];
+        segments.push(Segment::LineTo(b + normals[edge] * distance));
+
+        let has_next = if closed { true } else { edge + 1 < edge_count };
+        if has_next {
+            let next_edge = (edge + 1) % edge_count;
+            join_vertex(
+                pts[(edge + 1) % n],
+                dirs[edge],
+                dirs[next_edge],
+                distance,
+                join,
+                miter_limit,
+                &mut segments,
+            );
+        }
+    }
+    segments
+}
+
+/// Appends the geometry bridging the end of one offset edge to the start of
+/// the next, at `vertex`. Concave corners (where the two offset edges would
+/// otherwise overlap) are always simply bevelled; `join` only shapes convex
+/// corners.
+fn join_vertex(
+    vertex: Point,
+    d_in: Vec2,
+    d_out: Vec2,
+    distance: f64,
+    join: LineJoin,
+    miter_limit: f64,
+    segments: &mut Vec<Segment>,
+) {
+    let n_in = Vec2::new(-d_in.y, d_in.x);
+    let n_out = Vec2::new(-d_out.y, d_out.x);
+    let p_in = vertex + n_in * distance;
+    let p_out = vertex + n_out * distance;
+
+    let convex = cross(d_in, d_out) * distance < 0.0;
+    if !convex {
+        segments.push(Segment::LineTo(p_out));
+        return;
+    }
+
+    match join {
+        LineJoin::Bevel => segments.push(Segment::LineTo(p_out)),
+        LineJoin::Miter => {
+            if let Some(point) = line_intersection(p_in, d_in, p_out, d_out) {
+                if length(point - vertex) <= miter_limit * distance.abs() {
+                    segments.push(Segment::LineTo(point));
+                }
+            }
+            segments.push(Segment::LineTo(p_out));
+        }
+        LineJoin::Round => {
+            let a0 = (p_in.y - vertex.y).atan2(p_in.x - vertex.x);
+            let delta = signed_angle_between(n_in, n_out);
+            arc_to_quadratics(vertex, distance.abs(), a0, a0 + delta, segments);
+        }
+    }
+}
+
+/// Approximates a circular arc centered on `center` from `start_angle` to
+/// `end_angle` (radians, signed) with one quadratic per 90° or less.
+fn arc_to_quadratics(
+    center: Point,
+    radius: f64,
+    start_angle: f64,
+    end_angle: f64,
+    out: &mut Vec<Segment>,
+) {
+    let total = end_angle - start_angle;
+    let steps = ((total.abs() / std::f64::consts::FRAC_PI_2).ceil() as usize).max(1);
+    let step_angle = total / steps as f64;
+
+    let mut angle = start_angle;
+    for _ in 0..steps {
+        let mid = angle + step_angle / 2.0;
+        let end = angle + step_angle;
+        let ctrl_radius = radius / (step_angle / 2.0).cos();
+        let ctrl = Point::new(
+            center.x + ctrl_radius * mid.cos(),
+            center.y + ctrl_radius * mid.sin(),
+        );
+        let to = Point::new(center.x + radius * end.cos(), center.y + radius * end.sin());
+        out.push(Segment::QuadTo(ctrl, to));
+        angle = end;
+    }
+}
+
+fn line_intersection(p1: Point, d1: Vec2, p2: Point, d2: Vec2) -> Option<Point> {
+    let denom = cross(d1, d2);
+    if denom.abs() < 1e-9 {
+        return None;
+    }
+    let diff = p2 - p1;
+    let t = cross(diff, d2) / denom;
+    Some(p1 + d1 * t)
+}
+
+fn unit(v: Vec2) -> Vec2 {
+    let len = length(v);
+    if len < 1e-9 {
+        Vec2::ZERO
+    } else {
+        Vec2::new(v.x / len, v.y / len)
+    }
+}
+
+fn length(v: Vec2) -> f64 {
+    (v.x * v.x + v.y * v.y).sqrt()
+}
+
+fn dot(a: Vec2, b: Vec2) -> f64 {
+    a.x * b.x + a.y * b.y
+}
+
+fn cross(a: Vec2, b: Vec2) -> f64 {
+    a.x * b.y - a.y * b.x
+}
+
+fn signed_angle_between(a: Vec2, b: Vec2) -> f64 {
+    cross(a, b).atan2(dot(a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use kurbo::Rect;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::Node;
+
+    #[test]
+    fn stroking_a_straight_open_segment_produces_a_rectangle() {
+        let mut drawing = Drawing::new();
+        drawing.contours.push(Contour::from_nodes(vec![
+            Node::new(0.0, 0.0, PointType::Move),
+            Node::new(10.0, 0.0, PointType::Line),
+        ]));
+
+        let stroked = drawing.stroke(4.0, LineCap::Butt, LineJoin::Miter, 4.0);
+
+        assert_eq!(stroked.contours.len(), 1);
+        assert_eq!(
+            stroked.contours[0].bounds(),
+            Rect::new(0.0, -2.0, 10.0, 2.0)
+        );
+    }
+
+    fn square_pts() -> Vec<Point> {
+        vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+        ]
+    }
+
+    #[test]
+    fn closed_ccw_contour_miters_the_true_outward_offset() {
+        // The true outward offset of a CCW square is the *negative*-distance
+        // side; its corners are convex and should get a sharp miter point
+        // exactly on the diagonal, not a chamfer.
+        let segments = offset_segments(&square_pts(), true, -1.0, LineJoin::Miter, 4.0);
+        let points: Vec<Point> = segments.iter().map(Segment::to).collect();
+        assert!(points.contains(&Point::new(11.0, -1.0)));
+    }
+
+    #[test]
+    fn closed_ccw_contour_bevels_the_inward_offset_regardless_of_join() {
+        // The positive-distance side is the concave (hole-facing) offset;
+        // it must always bevel even when Miter is requested, and must not
+        // zig-zag back past the un-offset corner.
+        let segments = offset_segments(&square_pts(), true, 1.0, LineJoin::Miter, 4.0);
+        let points: Vec<Point> = segments.iter().map(Segment::to).collect();
+        assert!(!points.contains(&Point::new(9.0, 1.0)));
+        assert!(points.contains(&Point::new(9.0, 0.0)));
+    }
+
+    #[test]
+    fn open_polyline_round_joins_only_the_convex_side_of_a_corner() {
+        // An L-shaped polyline turning left at (10, 0): the left-hand offset
+        // is the concave side of that turn and must stay a straight bevel,
+        // while the right-hand offset is convex and should get a round arc.
+        let pts = vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+        ];
+
+        let left = offset_segments(&pts, false, 1.0, LineJoin::Round, 4.0);
+        assert!(!left.iter().any(|s| matches!(s, Segment::QuadTo(..))));
+
+        let mut reversed = pts.clone();
+        reversed.reverse();
+        let right = offset_segments(&reversed, false, 1.0, LineJoin::Round, 4.0);
+        assert!(right.iter().any(|s| matches!(s, Segment::QuadTo(..))));
+    }
+}